@@ -0,0 +1,48 @@
+// SPDX-FileCopyrightText: 2025 Polytech Montpellier.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::Error;
+use crate::tokenizer::Span;
+
+// Pulls the `Span` out of an `Error`, if it carries one. Every variant that
+// is raised against a concrete piece of source — a token, a char, a lexeme,
+// or (for runtime errors) the `Expr`/`Statement` node that triggered them —
+// carries one; only the handful with no single token to point at (an
+// unreadable file, a missing line number, running out of tokens) don't.
+fn span_of(error: &Error) -> Option<Span> {
+    match error {
+        Error::DivisionByZero(span) => Some(*span),
+        Error::InvalidCharacter(_, span) => Some(*span),
+        Error::InvalidIdentifier(_, span) => Some(*span),
+        Error::InvalidNumber(_, span) => Some(*span),
+        Error::InvalidOperatorToken(_, span) => Some(*span),
+        Error::InvalidValueToken(_, span) => Some(*span),
+        Error::UndefinedVariable(_, span) => Some(*span),
+        Error::UnexpectedToken(_, span) => Some(*span),
+        Error::UnknownLine(_, span) => Some(*span),
+        Error::UnmatchedNext(_, span) => Some(*span),
+        Error::UnsupportedThenBranch(span) => Some(*span),
+        Error::UnterminatedStringLiteral(_, span) => Some(*span),
+        Error::InvalidSourceFile(_) | Error::MissingLineNumber | Error::EndOfInput => None,
+    }
+}
+
+// Renders `error` for a human to read, ariadne-style: the offending source
+// line followed by a caret under the exact column, when a span is
+// available. `source_lines` holds the original program text, indexed by
+// physical (1-based) line number.
+pub fn report(source_lines: &[String], error: &Error) {
+    match span_of(error) {
+        Some(span) => {
+            eprintln!("error: {error:?}");
+
+            if let Some(source_line) = source_lines.get(span.line.saturating_sub(1)) {
+                eprintln!("  --> line {}:{}", span.line, span.column);
+                eprintln!("   | {source_line}");
+                eprintln!("   | {}^", " ".repeat(span.column.saturating_sub(1)));
+            }
+        }
+        None => eprintln!("error: {error:?}"),
+    }
+}
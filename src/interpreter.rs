@@ -0,0 +1,504 @@
+// SPDX-FileCopyrightText: 2025 Polytech Montpellier.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use crate::Error;
+use crate::parser::{
+    BinaryOperator, ComparisonOperator, Condition, Expr, Program, Statement, UnaryOperator,
+};
+use crate::tokenizer::Span;
+
+// Variables are stored mapping the identifier char to its current value.
+pub type Variables = HashMap<char, f64>;
+
+// Tells the caller what should happen after a statement has run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExecutionFlow {
+    Continue,
+    Jump(u32, Span),
+    Halt,
+}
+
+// Evaluates an expression tree down to a single number, resolving variables
+// against the current environment.
+pub fn eval_expression(expression: &Expr, variables: &Variables) -> Result<f64, Error> {
+    match expression {
+        Expr::Number(n) => Ok(*n),
+        Expr::Variable(v, span) => variables
+            .get(v)
+            .copied()
+            .ok_or(Error::UndefinedVariable(*v, *span)),
+        Expr::Unary { op, expr, .. } => {
+            let value = eval_expression(expr, variables)?;
+            Ok(match op {
+                UnaryOperator::Negate => -value,
+            })
+        }
+        Expr::Binary {
+            left,
+            op,
+            right,
+            span,
+        } => {
+            let left = eval_expression(left, variables)?;
+            let right = eval_expression(right, variables)?;
+            apply_operator(op, left, right, *span)
+        }
+    }
+}
+
+// Evaluates an IF condition down to a boolean, resolving both sides against
+// the current environment.
+pub fn eval_condition(condition: &Condition, variables: &Variables) -> Result<bool, Error> {
+    let left = eval_expression(&condition.left, variables)?;
+    let right = eval_expression(&condition.right, variables)?;
+
+    Ok(match condition.op {
+        ComparisonOperator::Equal => left == right,
+        ComparisonOperator::NotEqual => left != right,
+        ComparisonOperator::LessThan => left < right,
+        ComparisonOperator::LessOrEqual => left <= right,
+        ComparisonOperator::GreaterThan => left > right,
+        ComparisonOperator::GreaterOrEqual => left >= right,
+    })
+}
+
+fn apply_operator(operator: &BinaryOperator, left: f64, right: f64, span: Span) -> Result<f64, Error> {
+    match operator {
+        BinaryOperator::Add => Ok(left + right),
+        BinaryOperator::Subtract => Ok(left - right),
+        BinaryOperator::Multiply => Ok(left * right),
+        BinaryOperator::Divide => {
+            if right == 0.0 {
+                Err(Error::DivisionByZero(span))
+            } else {
+                Ok(left / right)
+            }
+        }
+        BinaryOperator::Modulo => {
+            if right == 0.0 {
+                Err(Error::DivisionByZero(span))
+            } else {
+                Ok(left % right)
+            }
+        }
+        BinaryOperator::Exponent => Ok(left.powf(right)),
+    }
+}
+
+// Runs a single statement against the variable environment, threading it
+// through so that `LET` bindings are visible to statements that follow.
+pub fn execute(statement: &Statement, variables: &mut Variables) -> Result<ExecutionFlow, Error> {
+    match statement {
+        Statement::Let {
+            variable,
+            expression,
+        } => {
+            let value = eval_expression(expression, variables)?;
+            variables.insert(*variable, value);
+            Ok(ExecutionFlow::Continue)
+        }
+
+        Statement::PrintString { value } => {
+            println!("{value}");
+            Ok(ExecutionFlow::Continue)
+        }
+
+        Statement::PrintExpr { expression } => {
+            println!("{}", eval_expression(expression, variables)?);
+            Ok(ExecutionFlow::Continue)
+        }
+
+        Statement::Goto { line, span } => Ok(ExecutionFlow::Jump(*line, *span)),
+
+        Statement::If {
+            condition,
+            then_branch,
+        } => {
+            if eval_condition(condition, variables)? {
+                execute(then_branch, variables)
+            } else {
+                Ok(ExecutionFlow::Continue)
+            }
+        }
+
+        // FOR/NEXT need the program counter and loop stack that only
+        // `run_program` has, so it intercepts them before they ever reach
+        // here. The parser rejects FOR/NEXT as an IF's THEN branch, so
+        // this arm is only reached when a FOR or NEXT statement is passed
+        // to `execute` directly, and then it panics.
+        Statement::For { .. } | Statement::Next { .. } => {
+            unreachable!("FOR/NEXT are executed directly by run_program")
+        }
+
+        Statement::End => Ok(ExecutionFlow::Halt),
+    }
+}
+
+// Finds the position of `line` within the ascending `order` list of line
+// numbers, used to resolve `GOTO` targets.
+fn line_index(order: &[u32], line: u32, span: Span) -> Result<usize, Error> {
+    order
+        .binary_search(&line)
+        .map_err(|_| Error::UnknownLine(line, span))
+}
+
+// A single entry of the loop stack maintained while running a program:
+// which variable is being counted, the bound it's counted towards, the
+// amount it's incremented by, and where to jump back to on NEXT.
+struct LoopFrame {
+    variable: char,
+    to: f64,
+    step: f64,
+    body_start: usize,
+}
+
+// Scans forward from `pc` (the `FOR` itself) for the `NEXT` that closes it,
+// tracking nested `FOR`/`NEXT` pairs by depth so it skips over inner loops
+// rather than stopping at their `NEXT`. Used to jump an empty-range `FOR`
+// straight past its body without ever pushing a loop frame. Falls back to
+// the end of the program if there's no matching `NEXT`, same as running off
+// the end normally would.
+fn index_after_matching_next(program: &Program, order: &[u32], pc: usize) -> usize {
+    let mut depth = 0;
+
+    for (i, line) in order.iter().enumerate().skip(pc + 1) {
+        match &program[line] {
+            Statement::For { .. } => depth += 1,
+            Statement::Next { .. } if depth == 0 => return i + 1,
+            Statement::Next { .. } => depth -= 1,
+            _ => (),
+        }
+    }
+
+    order.len()
+}
+
+// Runs every statement of `program` in line-number order (as listed in
+// `order`), following `GOTO` jumps and `FOR`/`NEXT` loops via a program
+// counter, until an `END` statement halts it or the last line is reached.
+pub fn run_program(
+    program: &Program,
+    order: &[u32],
+    variables: &mut Variables,
+) -> Result<(), Error> {
+    let mut pc = 0;
+    let mut loop_stack: Vec<LoopFrame> = Vec::new();
+
+    while pc < order.len() {
+        let statement = &program[&order[pc]];
+
+        match statement {
+            Statement::For {
+                variable,
+                from,
+                to,
+                step,
+            } => {
+                let from = eval_expression(from, variables)?;
+                let to = eval_expression(to, variables)?;
+                let step = match step {
+                    Some(step) => eval_expression(step, variables)?,
+                    None => 1.0,
+                };
+
+                variables.insert(*variable, from);
+
+                // Mirrors the direction check `NEXT` makes on every
+                // iteration: if the range is already empty/inverted, the
+                // body should run zero times, not once.
+                let in_range = if step >= 0.0 { from <= to } else { from >= to };
+
+                if in_range {
+                    loop_stack.push(LoopFrame {
+                        variable: *variable,
+                        to,
+                        step,
+                        body_start: pc + 1,
+                    });
+
+                    pc += 1;
+                } else {
+                    pc = index_after_matching_next(program, order, pc);
+                }
+            }
+
+            Statement::Next { variable, span } => {
+                let frame = loop_stack
+                    .last()
+                    .filter(|frame| frame.variable == *variable)
+                    .ok_or(Error::UnmatchedNext(*variable, *span))?;
+
+                let next = variables
+                    .get(variable)
+                    .copied()
+                    .ok_or(Error::UndefinedVariable(*variable, *span))?
+                    + frame.step;
+
+                // A negative step counts down, so it should keep looping
+                // while it's still *above* the bound, not below it.
+                let continues = if frame.step >= 0.0 {
+                    next <= frame.to
+                } else {
+                    next >= frame.to
+                };
+
+                if continues {
+                    variables.insert(*variable, next);
+                    pc = frame.body_start;
+                } else {
+                    loop_stack.pop();
+                    pc += 1;
+                }
+            }
+
+            _ => match execute(statement, variables)? {
+                ExecutionFlow::Continue => pc += 1,
+                ExecutionFlow::Jump(line, span) => pc = line_index(order, line, span)?,
+                ExecutionFlow::Halt => return Ok(()),
+            },
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Error;
+    use crate::interpreter::{self, ExecutionFlow, Variables};
+    use crate::parser::{Program, Statement};
+    use crate::tokenizer::GlotLine;
+
+    fn statement(line: &str) -> Statement {
+        let glot_line = GlotLine::new(1, line).unwrap();
+        Statement::new(glot_line.into_tokens()).unwrap()
+    }
+
+    #[test]
+    fn test_execute_let_stores_value() -> Result<(), Error> {
+        let mut variables = Variables::new();
+        interpreter::execute(&statement("LET A = 2 + 3"), &mut variables)?;
+
+        assert_eq!(variables.get(&'A'), Some(&5.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_execute_let_stores_fractional_value() -> Result<(), Error> {
+        let mut variables = Variables::new();
+        interpreter::execute(&statement("LET A = 1 / 4"), &mut variables)?;
+
+        assert_eq!(variables.get(&'A'), Some(&0.25));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_execute_let_modulo() -> Result<(), Error> {
+        let mut variables = Variables::new();
+        interpreter::execute(&statement("LET A = 7 % 3"), &mut variables)?;
+
+        assert_eq!(variables.get(&'A'), Some(&1.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_execute_let_exponent() -> Result<(), Error> {
+        let mut variables = Variables::new();
+        interpreter::execute(&statement("LET A = 2 ^ 10"), &mut variables)?;
+
+        assert_eq!(variables.get(&'A'), Some(&1024.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_execute_let_unary_minus() -> Result<(), Error> {
+        let mut variables = Variables::new();
+        interpreter::execute(&statement("LET A = 5"), &mut variables)?;
+        interpreter::execute(&statement("LET B = -A"), &mut variables)?;
+
+        assert_eq!(variables.get(&'B'), Some(&-5.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_execute_undefined_variable() {
+        let mut variables = Variables::new();
+        let result = interpreter::execute(&statement("LET A = B"), &mut variables);
+
+        assert!(matches!(result, Err(Error::UndefinedVariable('B', _))));
+    }
+
+    #[test]
+    fn test_execute_division_by_zero() {
+        let mut variables = Variables::new();
+        let result = interpreter::execute(&statement("LET A = 1 / 0"), &mut variables);
+
+        assert!(matches!(result, Err(Error::DivisionByZero(_))));
+    }
+
+    #[test]
+    fn test_execute_end_halts() -> Result<(), Error> {
+        let mut variables = Variables::new();
+        let flow = interpreter::execute(&statement("END"), &mut variables)?;
+
+        assert_eq!(flow, ExecutionFlow::Halt);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_execute_goto_requests_jump() -> Result<(), Error> {
+        let mut variables = Variables::new();
+        let flow = interpreter::execute(&statement("GOTO 30"), &mut variables)?;
+
+        assert!(matches!(flow, ExecutionFlow::Jump(30, _)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_program_follows_goto() -> Result<(), Error> {
+        // 10 LET A = 1
+        // 20 GOTO 40
+        // 30 LET A = 2
+        // 40 END
+        let program = Program::from([
+            (10, statement("LET A = 1")),
+            (20, statement("GOTO 40")),
+            (30, statement("LET A = 2")),
+            (40, statement("END")),
+        ]);
+        let order = [10, 20, 30, 40];
+        let mut variables = Variables::new();
+
+        interpreter::run_program(&program, &order, &mut variables)?;
+
+        assert_eq!(variables.get(&'A'), Some(&1.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_execute_if_true_runs_then_branch() -> Result<(), Error> {
+        let mut variables = Variables::new();
+        let flow = interpreter::execute(&statement("IF 1 < 2 THEN GOTO 50"), &mut variables)?;
+
+        assert!(matches!(flow, ExecutionFlow::Jump(50, _)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_execute_if_false_continues() -> Result<(), Error> {
+        let mut variables = Variables::new();
+        let flow = interpreter::execute(&statement("IF 1 > 2 THEN GOTO 50"), &mut variables)?;
+
+        assert_eq!(flow, ExecutionFlow::Continue);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_program_for_next_loop() -> Result<(), Error> {
+        // 10 LET S = 0
+        // 20 FOR I = 1 TO 3
+        // 30 LET S = S + I
+        // 40 NEXT I
+        // 50 END
+        let program = Program::from([
+            (10, statement("LET S = 0")),
+            (20, statement("FOR I = 1 TO 3")),
+            (30, statement("LET S = S + I")),
+            (40, statement("NEXT I")),
+            (50, statement("END")),
+        ]);
+        let order = [10, 20, 30, 40, 50];
+        let mut variables = Variables::new();
+
+        interpreter::run_program(&program, &order, &mut variables)?;
+
+        assert_eq!(variables.get(&'S'), Some(&6.0));
+        assert_eq!(variables.get(&'I'), Some(&3.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_program_unmatched_next() {
+        let program = Program::from([(10, statement("NEXT I"))]);
+        let order = [10];
+        let mut variables = Variables::new();
+
+        let result = interpreter::run_program(&program, &order, &mut variables);
+
+        assert!(matches!(result, Err(Error::UnmatchedNext('I', _))));
+    }
+
+    #[test]
+    fn test_run_program_unknown_line() {
+        let program = Program::from([(10, statement("GOTO 99"))]);
+        let order = [10];
+        let mut variables = Variables::new();
+
+        let result = interpreter::run_program(&program, &order, &mut variables);
+
+        assert!(matches!(result, Err(Error::UnknownLine(99, _))));
+    }
+
+    #[test]
+    fn test_run_program_for_next_empty_range_skips_body() -> Result<(), Error> {
+        // 10 LET S = 0
+        // 20 FOR I = 1 TO 0
+        // 30 LET S = S + 1
+        // 40 NEXT I
+        // 50 END
+        let program = Program::from([
+            (10, statement("LET S = 0")),
+            (20, statement("FOR I = 1 TO 0")),
+            (30, statement("LET S = S + 1")),
+            (40, statement("NEXT I")),
+            (50, statement("END")),
+        ]);
+        let order = [10, 20, 30, 40, 50];
+        let mut variables = Variables::new();
+
+        interpreter::run_program(&program, &order, &mut variables)?;
+
+        assert_eq!(variables.get(&'S'), Some(&0.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_program_for_next_loop_negative_step() -> Result<(), Error> {
+        // 10 LET S = 0
+        // 20 FOR I = 3 TO 1 STEP -1
+        // 30 LET S = S + I
+        // 40 NEXT I
+        // 50 END
+        let program = Program::from([
+            (10, statement("LET S = 0")),
+            (20, statement("FOR I = 3 TO 1 STEP -1")),
+            (30, statement("LET S = S + I")),
+            (40, statement("NEXT I")),
+            (50, statement("END")),
+        ]);
+        let order = [10, 20, 30, 40, 50];
+        let mut variables = Variables::new();
+
+        interpreter::run_program(&program, &order, &mut variables)?;
+
+        assert_eq!(variables.get(&'S'), Some(&6.0));
+        assert_eq!(variables.get(&'I'), Some(&1.0));
+
+        Ok(())
+    }
+}
@@ -6,24 +6,35 @@ use std::iter::Peekable;
 use std::path::PathBuf;
 use std::vec::IntoIter;
 
+pub mod diagnostics;
+pub mod interpreter;
 pub mod parser;
 pub mod tokenizer;
 
-use tokenizer::Token;
+use tokenizer::{PositionedToken, Span, Token};
 
 #[derive(Debug, PartialEq)]
 pub enum Error {
-    InvalidCharacter(char),
-    InvalidIdentifier(String),
-    InvalidNumber(String),
-    InvalidOperatorToken(Token),
+    DivisionByZero(Span),
+    InvalidCharacter(char, Span),
+    InvalidIdentifier(String, Span),
+    InvalidNumber(String, Span),
+    InvalidOperatorToken(Token, Span),
     InvalidSourceFile(PathBuf),
-    InvalidValueToken(Token),
-    UnexpectedToken(Token),
+    InvalidValueToken(Token, Span),
+    MissingLineNumber,
+    UndefinedVariable(char, Span),
+    UnexpectedToken(Token, Span),
+    UnknownLine(u32, Span),
+    UnmatchedNext(char, Span),
+    UnsupportedThenBranch(Span),
+    UnterminatedStringLiteral(String, Span),
     EndOfInput,
 }
 
 // Helper to consume next token or return error
-fn consume_token(tokens_iter: &mut Peekable<IntoIter<Token>>) -> Result<Token, Error> {
+fn consume_token(
+    tokens_iter: &mut Peekable<IntoIter<PositionedToken>>,
+) -> Result<PositionedToken, Error> {
     tokens_iter.next().ok_or(Error::EndOfInput)
 }
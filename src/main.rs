@@ -5,24 +5,38 @@
 use std::fs::File;
 use std::io::BufRead;
 use std::io::BufReader;
+use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
+use std::process::ExitCode;
 
 use clap::Parser;
 use glot::Error;
+use glot::diagnostics;
+use glot::interpreter::{self, ExecutionFlow, Variables};
+use glot::parser::{self, Program, Statement};
 use glot::tokenizer::GlotLine;
 
 #[derive(Parser)]
 #[command(version, about, long_about = None)]
 struct Cli {
-    /// glot source file
+    /// glot source file; omit to start an interactive REPL
     #[arg(short, long, value_name = "FILE")]
-    source: PathBuf,
+    source: Option<PathBuf>,
+
+    /// Print each line's tokens instead of running the program
+    #[arg(long)]
+    dump_tokens: bool,
+
+    /// Print each line's parsed statement instead of running the program
+    #[arg(long)]
+    dump_ast: bool,
 }
 
 #[derive(Debug, Clone)]
 struct Glotter {
     source: PathBuf,
+    source_lines: Vec<String>,
     lines: Vec<GlotLine>,
 }
 
@@ -30,6 +44,7 @@ impl Glotter {
     pub fn new_from_file(source_path: &Path) -> Result<Self, Error> {
         Ok(Glotter {
             source: source_path.to_path_buf(),
+            source_lines: Vec::new(),
             lines: Vec::new(),
         })
     }
@@ -39,24 +54,136 @@ impl Glotter {
             .map_err(|_| Error::InvalidSourceFile(self.source.clone()))?;
         let source = BufReader::new(source_file);
 
-        for line in source.lines() {
+        for (line_number, line) in source.lines().enumerate() {
             let line = line.unwrap();
-            self.lines.push(GlotLine::new(&line)?);
+            let glot_line = GlotLine::new(line_number + 1, &line);
+            self.source_lines.push(line);
+            self.lines.push(glot_line?);
+        }
+
+        Ok(())
+    }
+
+    // Prints the tokens produced for every line, one line of output each,
+    // without parsing or running anything.
+    pub fn dump_tokens(self) {
+        for line in self.lines {
+            println!("{:?}", line.into_tokens());
+        }
+    }
+
+    // Parses every line into its line-numbered statement and prints it,
+    // without running anything.
+    pub fn dump_ast(self) -> Result<(), Error> {
+        for line in self.lines {
+            let (line_number, statement) = parser::parse_line(line.into_tokens())?;
+            println!("{line_number:?}: {statement:?}");
         }
 
         Ok(())
     }
+
+    // Parses every tokenized line into a line-numbered statement, then runs
+    // the resulting program in ascending line-number order.
+    pub fn run(self) -> Result<(), Error> {
+        let mut program: Program = Program::new();
+        let mut order = Vec::new();
+
+        for line in self.lines {
+            let (line_number, statement) = parser::parse_line(line.into_tokens())?;
+            let line_number = line_number.ok_or(Error::MissingLineNumber)?;
+
+            program.insert(line_number, statement);
+            order.push(line_number);
+        }
+
+        order.sort_unstable();
+
+        let mut variables = Variables::new();
+        interpreter::run_program(&program, &order, &mut variables)
+    }
 }
 
-fn main() -> Result<(), Error> {
-    let cli = Cli::parse();
+fn run_file(source: &Path, dump_tokens: bool, dump_ast: bool) -> Result<(), (Error, Vec<String>)> {
+    let mut glotter = Glotter::new_from_file(source).map_err(|e| (e, Vec::new()))?;
+    glotter
+        .tokenize()
+        .map_err(|e| (e, glotter.source_lines.clone()))?;
 
-    let mut glotter = Glotter::new_from_file(&cli.source)?;
-    glotter.tokenize()?;
+    let source_lines = glotter.source_lines.clone();
 
-    for line in glotter.lines {
-        println!("{:?}", line);
+    if dump_tokens {
+        glotter.dump_tokens();
+        Ok(())
+    } else if dump_ast {
+        glotter.dump_ast().map_err(|e| (e, source_lines))
+    } else {
+        glotter.run().map_err(|e| (e, source_lines))
     }
+}
 
-    Ok(())
+// A line-at-a-time REPL: each entered line is tokenized, parsed and run
+// immediately against a `Variables` environment that persists across
+// prompts. Unlike `run_file`, there's no program map to jump around in, so
+// `FOR`/`NEXT`/`GOTO` are rejected instead of executed.
+fn run_repl() {
+    let mut variables = Variables::new();
+    let stdin = std::io::stdin();
+
+    loop {
+        print!("glot> ");
+        std::io::stdout().flush().ok();
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).unwrap_or(0) == 0 {
+            break;
+        }
+
+        let line = line.trim_end();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Err(error) = run_repl_line(line, &mut variables) {
+            diagnostics::report(&[line.to_string()], &error);
+        }
+    }
+}
+
+fn run_repl_line(line: &str, variables: &mut Variables) -> Result<(), Error> {
+    let tokens = GlotLine::new(1, line)?.into_tokens();
+    let statement = Statement::new(tokens)?;
+
+    match statement {
+        Statement::Goto { .. } | Statement::For { .. } | Statement::Next { .. } => {
+            eprintln!("GOTO/FOR/NEXT need a full program; run one with --source instead");
+            Ok(())
+        }
+        statement => match interpreter::execute(&statement, variables)? {
+            ExecutionFlow::Continue => Ok(()),
+            // Only an IF's THEN branch can still ask for a jump here.
+            ExecutionFlow::Jump(_, _) => {
+                eprintln!("GOTO/FOR/NEXT need a full program; run one with --source instead");
+                Ok(())
+            }
+            ExecutionFlow::Halt => std::process::exit(0),
+        },
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let Some(source) = &cli.source else {
+        run_repl();
+        return ExitCode::SUCCESS;
+    };
+
+    match run_file(source, cli.dump_tokens, cli.dump_ast) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err((error, source_lines)) => {
+            diagnostics::report(&source_lines, &error);
+            ExitCode::FAILURE
+        }
+    }
 }
@@ -4,38 +4,44 @@
 
 use crate::Error;
 use crate::consume_token;
-use crate::tokenizer::Token;
+use crate::tokenizer::{PositionedToken, Span, Token};
 
+use std::collections::HashMap;
 use std::iter::Peekable;
 use std::vec::IntoIter;
 
 // glot expressions.
 // A glot expression can be assigned to a variable, or used as an operand.
-//   A, A + B pr A + B * 10 are valid expressions.
+//   A, A + B or A + B * 10 are valid expressions.
 //
-// The grammatical definition of an expression is:
-//   expression      ::= term { ( "+" | "-" | "*" | "/" ) term }
-// A glot expression always starts with a `term` (a variable or a number), followed by
-// a series of (`binary operator`, `term`) couples.
+// Expressions are parsed into a tree by precedence climbing, so `*` and `/`
+// bind tighter than `+` and `-` regardless of how they're written out.
 
 // A number or a variable in an expression.
 // A, 10 and B in `A + 10 * B`
 #[derive(Debug, Clone, PartialEq)]
 pub enum Term {
-    Number(u64),
-    Variable(char),
+    Number(f64),
+    Variable(char, Span),
 }
 
 impl Term {
-    pub fn new(tokens_iter: &mut Peekable<IntoIter<Token>>) -> Result<Self, Error> {
-        match consume_token(tokens_iter)? {
+    pub fn new(tokens_iter: &mut Peekable<IntoIter<PositionedToken>>) -> Result<Self, Error> {
+        let positioned = consume_token(tokens_iter)?;
+        match positioned.token {
             Token::Number(n) => Ok(Term::Number(n)),
-            Token::Identifier(v) => Ok(Term::Variable(v)),
-            t => Err(Error::InvalidValueToken(t)),
+            Token::Identifier(v) => Ok(Term::Variable(v, positioned.span)),
+            t => Err(Error::InvalidValueToken(t, positioned.span)),
         }
     }
 }
 
+// Operators applying to a single operand. `-A` in `LET B = -A`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UnaryOperator {
+    Negate,
+}
+
 // Operators used within expressions
 #[derive(Debug, Clone, PartialEq)]
 pub enum BinaryOperator {
@@ -43,152 +49,560 @@ pub enum BinaryOperator {
     Subtract,
     Multiply,
     Divide,
+    Modulo,
+    Exponent,
 }
 
 impl BinaryOperator {
-    pub fn new(tokens_iter: &mut Peekable<IntoIter<Token>>) -> Result<Self, Error> {
-        match consume_token(tokens_iter)? {
+    pub fn new(tokens_iter: &mut Peekable<IntoIter<PositionedToken>>) -> Result<Self, Error> {
+        let positioned = consume_token(tokens_iter)?;
+        match positioned.token {
             Token::OperatorPlus => Ok(BinaryOperator::Add),
             Token::OperatorMinus => Ok(BinaryOperator::Subtract),
             Token::OperatorMultiply => Ok(BinaryOperator::Multiply),
             Token::OperatorDivide => Ok(BinaryOperator::Divide),
-            t => Err(Error::InvalidOperatorToken(t)),
+            Token::OperatorModulo => Ok(BinaryOperator::Modulo),
+            Token::OperatorExponent => Ok(BinaryOperator::Exponent),
+            t => Err(Error::InvalidOperatorToken(t, positioned.span)),
         }
     }
 }
 
+// Operators joining the two sides of an IF condition
 #[derive(Debug, Clone, PartialEq)]
-enum ExpressionItem {
-    Term(Term),
-    Operator(BinaryOperator),
+pub enum ComparisonOperator {
+    Equal,
+    NotEqual,
+    LessThan,
+    LessOrEqual,
+    GreaterThan,
+    GreaterOrEqual,
 }
 
-// A glot expression.
-// Example: `A + 10 * B` -> [Value(A), Operator(Add), Value(10), Operator(Multiply), Value(B)]
-#[derive(Debug, Clone, PartialEq)]
-struct Expression {
-    items: Vec<ExpressionItem>,
+impl ComparisonOperator {
+    pub fn new(tokens_iter: &mut Peekable<IntoIter<PositionedToken>>) -> Result<Self, Error> {
+        let positioned = consume_token(tokens_iter)?;
+        match positioned.token {
+            Token::OperatorEqual => Ok(ComparisonOperator::Equal),
+            Token::OperatorNotEqual => Ok(ComparisonOperator::NotEqual),
+            Token::OperatorLess => Ok(ComparisonOperator::LessThan),
+            Token::OperatorLessEqual => Ok(ComparisonOperator::LessOrEqual),
+            Token::OperatorGreater => Ok(ComparisonOperator::GreaterThan),
+            Token::OperatorGreaterEqual => Ok(ComparisonOperator::GreaterOrEqual),
+            t => Err(Error::InvalidOperatorToken(t, positioned.span)),
+        }
+    }
+}
+
+// A parsed glot expression tree.
+// Example: `A + 10 * B` -> Binary(Variable(A), Add, Binary(Number(10), Multiply, Variable(B)))
+#[derive(Debug, Clone)]
+pub enum Expr {
+    Number(f64),
+    Variable(char, Span),
+    Unary {
+        op: UnaryOperator,
+        expr: Box<Expr>,
+        span: Span,
+    },
+    Binary {
+        left: Box<Expr>,
+        op: BinaryOperator,
+        right: Box<Expr>,
+        span: Span,
+    },
+}
+
+// Spans mark *where* a node came from, not *what* it means, so two
+// expressions built from different source positions can still be equal.
+impl PartialEq for Expr {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Expr::Number(a), Expr::Number(b)) => a == b,
+            (Expr::Variable(a, _), Expr::Variable(b, _)) => a == b,
+            (
+                Expr::Unary {
+                    op: op_a,
+                    expr: expr_a,
+                    ..
+                },
+                Expr::Unary {
+                    op: op_b,
+                    expr: expr_b,
+                    ..
+                },
+            ) => op_a == op_b && expr_a == expr_b,
+            (
+                Expr::Binary {
+                    left: left_a,
+                    op: op_a,
+                    right: right_a,
+                    ..
+                },
+                Expr::Binary {
+                    left: left_b,
+                    op: op_b,
+                    right: right_b,
+                    ..
+                },
+            ) => left_a == left_b && op_a == op_b && right_a == right_b,
+            _ => false,
+        }
+    }
 }
 
-impl Expression {
-    pub fn new(tokens_iter: &mut Peekable<IntoIter<Token>>) -> Result<Self, Error> {
-        let mut items = Vec::new();
+impl Expr {
+    pub fn new(tokens_iter: &mut Peekable<IntoIter<PositionedToken>>) -> Result<Self, Error> {
+        parse_expr(tokens_iter, 1)
+    }
+}
 
-        // First item must be a term
-        let first_term = Term::new(tokens_iter)?;
-        items.push(ExpressionItem::Term(first_term));
+// The binding power of each binary operator: `*`/`/`/`%` bind tighter than
+// `+`/`-`, and `^` binds tighter still. Unary `-` binds tighter than all of
+// them, since it's parsed directly by `parse_primary`.
+fn precedence(token: &Token) -> Option<u8> {
+    match token {
+        Token::OperatorPlus | Token::OperatorMinus => Some(1),
+        Token::OperatorMultiply | Token::OperatorDivide | Token::OperatorModulo => Some(2),
+        Token::OperatorExponent => Some(3),
+        _ => None,
+    }
+}
 
-        loop {
-            if let Some(_token) = tokens_iter.peek() {
-                let operator = BinaryOperator::new(tokens_iter)?;
-                let term = Term::new(tokens_iter)?;
+// Parses a single operand: a `Term`, or a `-` applied to another primary
+// (so `- - A` and `-5` both work, the same as most languages).
+fn parse_primary(tokens_iter: &mut Peekable<IntoIter<PositionedToken>>) -> Result<Expr, Error> {
+    if let Some(PositionedToken {
+        token: Token::OperatorMinus,
+        ..
+    }) = tokens_iter.peek()
+    {
+        let minus = consume_token(tokens_iter)?;
+        let expr = parse_primary(tokens_iter)?;
+
+        return Ok(Expr::Unary {
+            op: UnaryOperator::Negate,
+            expr: Box::new(expr),
+            span: minus.span,
+        });
+    }
 
-                items.push(ExpressionItem::Operator(operator));
-                items.push(ExpressionItem::Term(term));
-            } else {
-                break;
-            }
-        }
+    Ok(match Term::new(tokens_iter)? {
+        Term::Number(n) => Expr::Number(n),
+        Term::Variable(v, span) => Expr::Variable(v, span),
+    })
+}
 
-        Ok(Expression { items })
+// Precedence climbing: parses a leading primary as the left operand, then
+// keeps folding in binary operators whose precedence is at least `min_prec`.
+// The right operand of each operator is parsed with `min_prec` raised to
+// `op_prec + 1`, which makes operators left-associative.
+fn parse_expr(
+    tokens_iter: &mut Peekable<IntoIter<PositionedToken>>,
+    min_prec: u8,
+) -> Result<Expr, Error> {
+    let mut left = parse_primary(tokens_iter)?;
+
+    loop {
+        let op_prec = match tokens_iter.peek().map(|pt| &pt.token).and_then(precedence) {
+            Some(op_prec) if op_prec >= min_prec => op_prec,
+            _ => break,
+        };
+
+        // Safe to unwrap: `precedence` above only matched because a token is there.
+        let span = tokens_iter.peek().unwrap().span;
+        let op = BinaryOperator::new(tokens_iter)?;
+        let right = parse_expr(tokens_iter, op_prec + 1)?;
+
+        left = Expr::Binary {
+            left: Box::new(left),
+            op,
+            right: Box::new(right),
+            span,
+        };
+    }
+
+    Ok(left)
+}
+
+// The condition of an IF statement: two expressions joined by a comparison
+// operator, e.g. `A < B + 1`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Condition {
+    pub left: Expr,
+    pub op: ComparisonOperator,
+    pub right: Expr,
+}
+
+impl Condition {
+    pub fn new(tokens_iter: &mut Peekable<IntoIter<PositionedToken>>) -> Result<Self, Error> {
+        let left = Expr::new(tokens_iter)?;
+        let op = ComparisonOperator::new(tokens_iter)?;
+        let right = Expr::new(tokens_iter)?;
+
+        Ok(Condition { left, op, right })
     }
 }
 
 // Represents a complete, parsed command in `glot`.
 // This is the output of the parser, built from Tokens and Expressions.
-#[derive(Debug, Clone)] // PartialEq might be tricky due to Vec in Expression
+#[derive(Debug, Clone)]
 pub enum Statement {
     // LET <VAR> = <expression>
-    Let {
-        variable: char,
-        expression: Expression,
-    },
+    Let { variable: char, expression: Expr },
 
     // PRINT "<string>"
     PrintString { value: String },
 
     // PRINT <expression>
-    PrintExpr { expression: Expression },
+    PrintExpr { expression: Expr },
+
+    // GOTO <line>
+    Goto { line: u32, span: Span },
+
+    // IF <condition> THEN <statement>
+    If {
+        condition: Condition,
+        then_branch: Box<Statement>,
+    },
+
+    // FOR <VAR> = <expression> TO <expression> [STEP <expression>]
+    For {
+        variable: char,
+        from: Expr,
+        to: Expr,
+        step: Option<Expr>,
+    },
+
+    // NEXT <VAR>
+    Next { variable: char, span: Span },
 
     // END
     End,
 }
 
+// As with `Expr`, spans don't participate in equality.
+impl PartialEq for Statement {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Statement::Let {
+                    variable: var_a,
+                    expression: expr_a,
+                },
+                Statement::Let {
+                    variable: var_b,
+                    expression: expr_b,
+                },
+            ) => var_a == var_b && expr_a == expr_b,
+            (Statement::PrintString { value: a }, Statement::PrintString { value: b }) => a == b,
+            (Statement::PrintExpr { expression: a }, Statement::PrintExpr { expression: b }) => {
+                a == b
+            }
+            (Statement::Goto { line: a, .. }, Statement::Goto { line: b, .. }) => a == b,
+            (
+                Statement::If {
+                    condition: cond_a,
+                    then_branch: then_a,
+                },
+                Statement::If {
+                    condition: cond_b,
+                    then_branch: then_b,
+                },
+            ) => cond_a == cond_b && then_a == then_b,
+            (
+                Statement::For {
+                    variable: var_a,
+                    from: from_a,
+                    to: to_a,
+                    step: step_a,
+                },
+                Statement::For {
+                    variable: var_b,
+                    from: from_b,
+                    to: to_b,
+                    step: step_b,
+                },
+            ) => var_a == var_b && from_a == from_b && to_a == to_b && step_a == step_b,
+            (Statement::Next { variable: a, .. }, Statement::Next { variable: b, .. }) => a == b,
+            (Statement::End, Statement::End) => true,
+            _ => false,
+        }
+    }
+}
+
 impl Statement {
-    pub fn new(tokens: Vec<Token>) -> Result<Self, Error> {
+    pub fn new(tokens: Vec<PositionedToken>) -> Result<Self, Error> {
         let mut tokens_iter = tokens.into_iter().peekable();
         let first_token = consume_token(&mut tokens_iter)?;
 
-        match first_token {
+        match first_token.token {
             Token::KeywordLet => {
                 // LET <VAR> = <expression>
                 let variable = match consume_token(&mut tokens_iter)? {
-                    Token::Identifier(v) => v,
-                    t => return Err(Error::UnexpectedToken(t)),
+                    PositionedToken {
+                        token: Token::Identifier(v),
+                        ..
+                    } => v,
+                    pt => return Err(Error::UnexpectedToken(pt.token, pt.span)),
                 };
 
                 match consume_token(&mut tokens_iter)? {
                     // Next token must be an Equals
-                    Token::Equals => (),
-                    t => return Err(Error::UnexpectedToken(t)),
+                    PositionedToken {
+                        token: Token::Equals,
+                        ..
+                    } => (),
+                    pt => return Err(Error::UnexpectedToken(pt.token, pt.span)),
                 };
 
-                let expression = Expression::new(&mut tokens_iter)?;
-                Ok(Statement::Let { variable, expression })
+                let expression = Expr::new(&mut tokens_iter)?;
+                Ok(Statement::Let {
+                    variable,
+                    expression,
+                })
             }
 
             Token::KeywordPrint => {
-                // PRINT <StringLiteral>
-                if let Some(Token::StringLiteral(_)) = tokens_iter.peek().cloned() {
+                // PRINT <StringLiteral> or PRINT <expression>
+                if let Some(PositionedToken {
+                    token: Token::StringLiteral(_),
+                    ..
+                }) = tokens_iter.peek()
+                {
                     match consume_token(&mut tokens_iter)? {
-                        Token::StringLiteral(s) => Ok(Statement::PrintString { value: s }),
+                        PositionedToken {
+                            token: Token::StringLiteral(s),
+                            ..
+                        } => Ok(Statement::PrintString { value: s }),
                         _ => unreachable!(), // Should have been caught by peek
                     }
                 } else {
-                    unreachable!()
+                    let expression = Expr::new(&mut tokens_iter)?;
+                    Ok(Statement::PrintExpr { expression })
+                }
+            }
+
+            Token::KeywordGoto => {
+                // GOTO <line>
+                match consume_token(&mut tokens_iter)? {
+                    PositionedToken {
+                        token: Token::Number(n),
+                        span,
+                    } => Ok(Statement::Goto {
+                        line: n as u32,
+                        span,
+                    }),
+                    pt => Err(Error::UnexpectedToken(pt.token, pt.span)),
+                }
+            }
+
+            Token::KeywordIf => {
+                // IF <condition> THEN <statement>
+                let condition = Condition::new(&mut tokens_iter)?;
+
+                match consume_token(&mut tokens_iter)? {
+                    PositionedToken {
+                        token: Token::KeywordThen,
+                        ..
+                    } => (),
+                    pt => return Err(Error::UnexpectedToken(pt.token, pt.span)),
+                };
+
+                // FOR/NEXT need the program counter and loop stack that only
+                // `run_program` maintains, so they can't be nested inside an
+                // IF's THEN branch the way GOTO or a plain statement can.
+                if let Some(PositionedToken {
+                    token: Token::KeywordFor | Token::KeywordNext,
+                    span,
+                }) = tokens_iter.peek()
+                {
+                    return Err(Error::UnsupportedThenBranch(*span));
+                }
+
+                let then_branch = Statement::new(tokens_iter.collect())?;
+                Ok(Statement::If {
+                    condition,
+                    then_branch: Box::new(then_branch),
+                })
+            }
+
+            Token::KeywordFor => {
+                // FOR <VAR> = <expression> TO <expression> [STEP <expression>]
+                let variable = match consume_token(&mut tokens_iter)? {
+                    PositionedToken {
+                        token: Token::Identifier(v),
+                        ..
+                    } => v,
+                    pt => return Err(Error::UnexpectedToken(pt.token, pt.span)),
+                };
+
+                match consume_token(&mut tokens_iter)? {
+                    PositionedToken {
+                        token: Token::Equals,
+                        ..
+                    } => (),
+                    pt => return Err(Error::UnexpectedToken(pt.token, pt.span)),
+                };
+
+                let from = Expr::new(&mut tokens_iter)?;
+
+                match consume_token(&mut tokens_iter)? {
+                    PositionedToken {
+                        token: Token::KeywordTo,
+                        ..
+                    } => (),
+                    pt => return Err(Error::UnexpectedToken(pt.token, pt.span)),
+                };
+
+                let to = Expr::new(&mut tokens_iter)?;
+
+                let step = if let Some(PositionedToken {
+                    token: Token::KeywordStep,
+                    ..
+                }) = tokens_iter.peek()
+                {
+                    consume_token(&mut tokens_iter)?;
+                    Some(Expr::new(&mut tokens_iter)?)
+                } else {
+                    None
+                };
+
+                Ok(Statement::For {
+                    variable,
+                    from,
+                    to,
+                    step,
+                })
+            }
+
+            Token::KeywordNext => {
+                // NEXT <VAR>
+                match consume_token(&mut tokens_iter)? {
+                    PositionedToken {
+                        token: Token::Identifier(v),
+                        span,
+                    } => Ok(Statement::Next {
+                        variable: v,
+                        span,
+                    }),
+                    pt => Err(Error::UnexpectedToken(pt.token, pt.span)),
                 }
             }
 
             Token::KeywordEnd => Ok(Statement::End),
 
-            t => Err(Error::UnexpectedToken(t)),
+            t => Err(Error::UnexpectedToken(t, first_token.span)),
         }
     }
 }
 
-//pub type Program = HashMap<u32, Statement>;
+// A glot program, keyed by its BASIC-style line numbers.
+pub type Program = HashMap<u32, Statement>;
+
+// Splits off a line's optional leading line-number token, then parses the
+// remainder as a statement. The line number is optional here; building a
+// `Program` needs one on every line, since the program is keyed by it.
+pub fn parse_line(mut tokens: Vec<PositionedToken>) -> Result<(Option<u32>, Statement), Error> {
+    let line_number = match tokens.first() {
+        Some(PositionedToken {
+            token: Token::Number(n),
+            ..
+        }) => {
+            let line_number = *n as u32;
+            tokens.remove(0);
+            Some(line_number)
+        }
+        _ => None,
+    };
 
-// Variables are stored mapping the identifier char to its f64 value
-//pub type Variables = HashMap<char, f64>;
+    let statement = Statement::new(tokens)?;
+    Ok((line_number, statement))
+}
 
 #[cfg(test)]
 mod tests {
     use crate::Error;
     use crate::parser::BinaryOperator;
-    use crate::parser::Expression;
-    use crate::parser::ExpressionItem;
-    use crate::parser::Term;
+    use crate::parser::ComparisonOperator;
+    use crate::parser::Condition;
+    use crate::parser::Expr;
+    use crate::parser::Statement;
+    use crate::parser::UnaryOperator;
+    use crate::parser::parse_line;
     use crate::tokenizer::GlotLine;
+    use crate::tokenizer::Span;
     use crate::tokenizer::Token;
 
     #[test]
-    fn test_expression_arithmetic() -> Result<(), Error> {
+    fn test_expression_precedence() -> Result<(), Error> {
         let line = "A + 10 * B";
-        let expected_items = [
-            ExpressionItem::Term(Term::Variable('A')),
-            ExpressionItem::Operator(BinaryOperator::Add),
-            ExpressionItem::Term(Term::Number(10)),
-            ExpressionItem::Operator(BinaryOperator::Multiply),
-            ExpressionItem::Term(Term::Variable('B')),
-        ];
 
-        let glot_line = GlotLine::new(&line)?;
-        let expression = Expression::new(&mut glot_line.tokens.into_iter().peekable())?;
+        let glot_line = GlotLine::new(1, line)?;
+        let expr = Expr::new(&mut glot_line.tokens.into_iter().peekable())?;
 
         assert_eq!(
-            expression,
-            Expression {
-                items: expected_items.to_vec()
+            expr,
+            Expr::Binary {
+                left: Box::new(Expr::Variable('A', Span::default())),
+                op: BinaryOperator::Add,
+                right: Box::new(Expr::Binary {
+                    left: Box::new(Expr::Number(10.0)),
+                    op: BinaryOperator::Multiply,
+                    right: Box::new(Expr::Variable('B', Span::default())),
+                    span: Span::default(),
+                }),
+                span: Span::default(),
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_expression_exponent_binds_tighter_than_multiply() -> Result<(), Error> {
+        let line = "A * B ^ 2";
+
+        let glot_line = GlotLine::new(1, line)?;
+        let expr = Expr::new(&mut glot_line.tokens.into_iter().peekable())?;
+
+        assert_eq!(
+            expr,
+            Expr::Binary {
+                left: Box::new(Expr::Variable('A', Span::default())),
+                op: BinaryOperator::Multiply,
+                right: Box::new(Expr::Binary {
+                    left: Box::new(Expr::Variable('B', Span::default())),
+                    op: BinaryOperator::Exponent,
+                    right: Box::new(Expr::Number(2.0)),
+                    span: Span::default(),
+                }),
+                span: Span::default(),
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_expression_left_associative() -> Result<(), Error> {
+        let line = "A - B - 1";
+
+        let glot_line = GlotLine::new(1, line)?;
+        let expr = Expr::new(&mut glot_line.tokens.into_iter().peekable())?;
+
+        assert_eq!(
+            expr,
+            Expr::Binary {
+                left: Box::new(Expr::Binary {
+                    left: Box::new(Expr::Variable('A', Span::default())),
+                    op: BinaryOperator::Subtract,
+                    right: Box::new(Expr::Variable('B', Span::default())),
+                    span: Span::default(),
+                }),
+                op: BinaryOperator::Subtract,
+                right: Box::new(Expr::Number(1.0)),
+                span: Span::default(),
             }
         );
 
@@ -198,15 +612,42 @@ mod tests {
     #[test]
     fn test_expression_variable() -> Result<(), Error> {
         let line = "A";
-        let expected_items = [ExpressionItem::Term(Term::Variable('A'))];
 
-        let glot_line = GlotLine::new(&line)?;
-        let expression = Expression::new(&mut glot_line.tokens.into_iter().peekable())?;
+        let glot_line = GlotLine::new(1, line)?;
+        let expr = Expr::new(&mut glot_line.tokens.into_iter().peekable())?;
+
+        assert_eq!(expr, Expr::Variable('A', Span::default()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_expression_stops_before_non_operator() -> Result<(), Error> {
+        let line = "A = 5";
+
+        let glot_line = GlotLine::new(1, line)?;
+        let mut tokens_iter = glot_line.tokens.into_iter().peekable();
+        let expr = Expr::new(&mut tokens_iter)?;
+
+        assert_eq!(expr, Expr::Variable('A', Span::default()));
+        assert_eq!(tokens_iter.next().map(|pt| pt.token), Some(Token::Equals));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_expression_unary_minus() -> Result<(), Error> {
+        let line = "-A";
+
+        let glot_line = GlotLine::new(1, line)?;
+        let expr = Expr::new(&mut glot_line.tokens.into_iter().peekable())?;
 
         assert_eq!(
-            expression,
-            Expression {
-                items: expected_items.to_vec()
+            expr,
+            Expr::Unary {
+                op: UnaryOperator::Negate,
+                expr: Box::new(Expr::Variable('A', Span::default())),
+                span: Span::default(),
             }
         );
 
@@ -214,13 +655,23 @@ mod tests {
     }
 
     #[test]
-    fn test_invalid_expression_assign() -> Result<(), Error> {
-        let line = "A = 5";
+    fn test_expression_double_unary_minus() -> Result<(), Error> {
+        let line = "--A";
+
+        let glot_line = GlotLine::new(1, line)?;
+        let expr = Expr::new(&mut glot_line.tokens.into_iter().peekable())?;
 
-        let glot_line = GlotLine::new(&line)?;
         assert_eq!(
-            Expression::new(&mut glot_line.tokens.into_iter().peekable()),
-            Err(Error::InvalidOperatorToken(Token::Equals))
+            expr,
+            Expr::Unary {
+                op: UnaryOperator::Negate,
+                expr: Box::new(Expr::Unary {
+                    op: UnaryOperator::Negate,
+                    expr: Box::new(Expr::Variable('A', Span::default())),
+                    span: Span::default(),
+                }),
+                span: Span::default(),
+            }
         );
 
         Ok(())
@@ -230,11 +681,13 @@ mod tests {
     fn test_invalid_expression_statement() -> Result<(), Error> {
         let line = "LET A = 5";
 
-        let glot_line = GlotLine::new(&line)?;
-        assert_eq!(
-            Expression::new(&mut glot_line.tokens.into_iter().peekable()),
-            Err(Error::InvalidValueToken(Token::KeywordLet))
-        );
+        let glot_line = GlotLine::new(1, line)?;
+        let result = Expr::new(&mut glot_line.tokens.into_iter().peekable());
+
+        assert!(matches!(
+            result,
+            Err(Error::InvalidValueToken(Token::KeywordLet, _))
+        ));
 
         Ok(())
     }
@@ -243,10 +696,185 @@ mod tests {
     fn test_invalid_expression_keyword() -> Result<(), Error> {
         let line = "PRINT";
 
-        let glot_line = GlotLine::new(&line)?;
+        let glot_line = GlotLine::new(1, line)?;
+        let result = Expr::new(&mut glot_line.tokens.into_iter().peekable());
+
+        assert!(matches!(
+            result,
+            Err(Error::InvalidValueToken(Token::KeywordPrint, _))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_line_with_number() -> Result<(), Error> {
+        let glot_line = GlotLine::new(1, "10 PRINT G")?;
+        let (line_number, statement) = parse_line(glot_line.into_tokens())?;
+
+        assert_eq!(line_number, Some(10));
+        assert!(matches!(
+            statement,
+            Statement::PrintExpr {
+                expression: Expr::Variable('G', _)
+            }
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_line_without_number() -> Result<(), Error> {
+        let glot_line = GlotLine::new(1, "END")?;
+        let (line_number, statement) = parse_line(glot_line.into_tokens())?;
+
+        assert_eq!(line_number, None);
+        assert!(matches!(statement, Statement::End));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_statement_goto() -> Result<(), Error> {
+        let glot_line = GlotLine::new(1, "GOTO 10")?;
+        let statement = Statement::new(glot_line.into_tokens())?;
+
+        assert_eq!(
+            statement,
+            Statement::Goto {
+                line: 10,
+                span: Span::default(),
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_statement_if_then_goto() -> Result<(), Error> {
+        let glot_line = GlotLine::new(1, "IF A < 10 THEN GOTO 20")?;
+        let statement = Statement::new(glot_line.into_tokens())?;
+
+        assert_eq!(
+            statement,
+            Statement::If {
+                condition: Condition {
+                    left: Expr::Variable('A', Span::default()),
+                    op: ComparisonOperator::LessThan,
+                    right: Expr::Number(10.0),
+                },
+                then_branch: Box::new(Statement::Goto {
+                    line: 20,
+                    span: Span::default(),
+                }),
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_statement_for_with_step() -> Result<(), Error> {
+        let glot_line = GlotLine::new(1, "FOR I = 1 TO 10 STEP 2")?;
+        let statement = Statement::new(glot_line.into_tokens())?;
+
+        assert_eq!(
+            statement,
+            Statement::For {
+                variable: 'I',
+                from: Expr::Number(1.0),
+                to: Expr::Number(10.0),
+                step: Some(Expr::Number(2.0)),
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_statement_for_with_negative_step() -> Result<(), Error> {
+        let glot_line = GlotLine::new(1, "FOR I = 3 TO 1 STEP -1")?;
+        let statement = Statement::new(glot_line.into_tokens())?;
+
+        assert_eq!(
+            statement,
+            Statement::For {
+                variable: 'I',
+                from: Expr::Number(3.0),
+                to: Expr::Number(1.0),
+                step: Some(Expr::Unary {
+                    op: UnaryOperator::Negate,
+                    expr: Box::new(Expr::Number(1.0)),
+                    span: Span::default(),
+                }),
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_statement_for_without_step() -> Result<(), Error> {
+        let glot_line = GlotLine::new(1, "FOR I = 1 TO 10")?;
+        let statement = Statement::new(glot_line.into_tokens())?;
+
+        assert_eq!(
+            statement,
+            Statement::For {
+                variable: 'I',
+                from: Expr::Number(1.0),
+                to: Expr::Number(10.0),
+                step: None,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_statement_next() -> Result<(), Error> {
+        let glot_line = GlotLine::new(1, "NEXT I")?;
+        let statement = Statement::new(glot_line.into_tokens())?;
+
         assert_eq!(
-            Expression::new(&mut glot_line.tokens.into_iter().peekable()),
-            Err(Error::InvalidValueToken(Token::KeywordPrint))
+            statement,
+            Statement::Next {
+                variable: 'I',
+                span: Span::default(),
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_statement_if_then_next_is_rejected() {
+        let glot_line = GlotLine::new(1, "IF 1 < 2 THEN NEXT I").unwrap();
+        let result = Statement::new(glot_line.into_tokens());
+
+        assert!(matches!(result, Err(Error::UnsupportedThenBranch(_))));
+    }
+
+    #[test]
+    fn test_statement_if_then_for_is_rejected() {
+        let glot_line = GlotLine::new(1, "IF 1 < 2 THEN FOR I = 1 TO 10").unwrap();
+        let result = Statement::new(glot_line.into_tokens());
+
+        assert!(matches!(result, Err(Error::UnsupportedThenBranch(_))));
+    }
+
+    #[test]
+    fn test_condition_comparison_operators() -> Result<(), Error> {
+        let glot_line = GlotLine::new(1, "A <> B")?;
+        let condition = Condition::new(&mut glot_line.tokens.into_iter().peekable())?;
+
+        assert_eq!(
+            condition,
+            Condition {
+                left: Expr::Variable('A', Span::default()),
+                op: ComparisonOperator::NotEqual,
+                right: Expr::Variable('B', Span::default()),
+            }
         );
 
         Ok(())
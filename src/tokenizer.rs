@@ -2,21 +2,41 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
+use std::iter::Peekable;
+use std::str::Chars;
+
 use crate::Error;
 
+// The source location of a single token: a 1-based physical line number
+// (as supplied by the caller, since `GlotLine` only ever sees one line of
+// source) and a 1-based column offset within that line.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum Token {
     // Keywords
     KeywordLet,   // LET
     KeywordPrint, // PRINT
     KeywordEnd,   // END
+    KeywordGoto,  // GOTO
+    KeywordIf,    // IF
+    KeywordThen,  // THEN
+    KeywordFor,   // FOR
+    KeywordTo,    // TO
+    KeywordStep,  // STEP
+    KeywordNext,  // NEXT
 
     // Variable
     // glot only supports single character variables
     Identifier(char),
 
     // Literals
-    Number(u64),
+    Number(f64),
+    StringLiteral(String),
 
     // Operators
     Equals, // assignment operator (not a comparator)
@@ -24,67 +44,207 @@ pub enum Token {
     OperatorMinus,
     OperatorMultiply,
     OperatorDivide,
+    OperatorModulo,   // %
+    OperatorExponent, // ^
+
+    // Comparison operators (only valid within an IF condition)
+    OperatorEqual,        // ==
+    OperatorNotEqual,     // <>
+    OperatorLess,         // <
+    OperatorLessEqual,    // <=
+    OperatorGreater,      // >
+    OperatorGreaterEqual, // >=
+}
+
+// A token together with where it was found in the source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionedToken {
+    pub token: Token,
+    pub span: Span,
+}
+
+// Consumes one character, advancing `column` so spans stay accurate.
+// glot's grammar is ASCII-only, so one `char` is always one column.
+fn advance(chars: &mut Peekable<Chars>, column: &mut usize) {
+    chars.next();
+    *column += 1;
 }
 
-#[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct GlotLine {
-    tokens: Vec<Token>,
+    pub(crate) tokens: Vec<PositionedToken>,
 }
 
 impl GlotLine {
-    pub fn new(line: &str) -> Result<Self, Error> {
+    /// Consumes the line, yielding its positioned tokens for parsing.
+    pub fn into_tokens(self) -> Vec<PositionedToken> {
+        self.tokens
+    }
+
+    pub fn new(line_number: usize, line: &str) -> Result<Self, Error> {
         let mut tokens = Vec::new();
         let mut chars = line.chars().peekable();
+        let mut column = 1usize;
 
         while let Some(&c) = chars.peek() {
+            let span = Span {
+                line: line_number,
+                column,
+            };
+
             match c {
                 ' ' | '\t' | '\r' | '\n' => {
                     // Move the iterator forward
-                    chars.next();
+                    advance(&mut chars, &mut column);
                 }
 
                 '+' => {
-                    tokens.push(Token::OperatorPlus);
-                    chars.next();
+                    tokens.push(PositionedToken {
+                        token: Token::OperatorPlus,
+                        span,
+                    });
+                    advance(&mut chars, &mut column);
                 }
                 '-' => {
-                    tokens.push(Token::OperatorMinus);
-                    chars.next();
+                    tokens.push(PositionedToken {
+                        token: Token::OperatorMinus,
+                        span,
+                    });
+                    advance(&mut chars, &mut column);
                 }
                 '*' => {
-                    tokens.push(Token::OperatorMultiply);
-                    chars.next();
+                    tokens.push(PositionedToken {
+                        token: Token::OperatorMultiply,
+                        span,
+                    });
+                    advance(&mut chars, &mut column);
                 }
                 '/' => {
-                    tokens.push(Token::OperatorDivide);
-                    chars.next();
+                    tokens.push(PositionedToken {
+                        token: Token::OperatorDivide,
+                        span,
+                    });
+                    advance(&mut chars, &mut column);
+                }
+                '%' => {
+                    tokens.push(PositionedToken {
+                        token: Token::OperatorModulo,
+                        span,
+                    });
+                    advance(&mut chars, &mut column);
+                }
+                '^' => {
+                    tokens.push(PositionedToken {
+                        token: Token::OperatorExponent,
+                        span,
+                    });
+                    advance(&mut chars, &mut column);
                 }
                 '=' => {
-                    tokens.push(Token::Equals);
-                    chars.next();
+                    advance(&mut chars, &mut column);
+
+                    let token = if chars.peek() == Some(&'=') {
+                        advance(&mut chars, &mut column);
+                        Token::OperatorEqual
+                    } else {
+                        Token::Equals
+                    };
+
+                    tokens.push(PositionedToken { token, span });
+                }
+                '<' => {
+                    advance(&mut chars, &mut column);
+
+                    let token = match chars.peek() {
+                        Some(&'=') => {
+                            advance(&mut chars, &mut column);
+                            Token::OperatorLessEqual
+                        }
+                        Some(&'>') => {
+                            advance(&mut chars, &mut column);
+                            Token::OperatorNotEqual
+                        }
+                        _ => Token::OperatorLess,
+                    };
+
+                    tokens.push(PositionedToken { token, span });
+                }
+                '>' => {
+                    advance(&mut chars, &mut column);
+
+                    let token = if chars.peek() == Some(&'=') {
+                        advance(&mut chars, &mut column);
+                        Token::OperatorGreaterEqual
+                    } else {
+                        Token::OperatorGreater
+                    };
+
+                    tokens.push(PositionedToken { token, span });
+                }
+
+                '"' => {
+                    // Consume the opening quote
+                    advance(&mut chars, &mut column);
+
+                    let mut value = String::new();
+                    let mut closed = false;
+
+                    while chars.peek().is_some() {
+                        let ch = chars.peek().copied().unwrap();
+                        advance(&mut chars, &mut column);
+
+                        if ch == '"' {
+                            closed = true;
+                            break;
+                        }
+
+                        value.push(ch);
+                    }
+
+                    if !closed {
+                        return Err(Error::UnterminatedStringLiteral(value, span));
+                    }
+
+                    tokens.push(PositionedToken {
+                        token: Token::StringLiteral(value),
+                        span,
+                    });
                 }
 
                 '0'..='9' => {
-                    // Build the string representing the number
+                    // Build the string representing the number, with at most
+                    // one `.` separating an integer part from a fractional one
                     let mut num_str = String::new();
+                    let mut seen_dot = false;
 
                     // Start peeking into the character stream
                     while let Some(&ch) = chars.peek() {
-                        // Exit the loop as soon as the next character is *not* a digit
-                        if !ch.is_ascii_digit() {
+                        if ch == '.' {
+                            // A second `.` makes this an invalid number
+                            if seen_dot {
+                                num_str.push(ch);
+                                advance(&mut chars, &mut column);
+                                return Err(Error::InvalidNumber(num_str, span));
+                            }
+                            seen_dot = true;
+                        } else if !ch.is_ascii_digit() {
+                            // Exit the loop as soon as the next character is
+                            // *not* a digit or the fraction's `.`
                             break;
                         }
 
-                        // Accumulate digits into the number string
+                        // Accumulate digits (and the `.`) into the number string
                         num_str.push(ch);
-                        chars.next();
+                        advance(&mut chars, &mut column);
                     }
 
                     // Check that this is a valid number
-                    match num_str.parse::<u64>() {
-                        Ok(num) => tokens.push(Token::Number(num)),
-                        Err(_) => return Err(Error::InvalidNumber(num_str)),
+                    match num_str.parse::<f64>() {
+                        Ok(num) => tokens.push(PositionedToken {
+                            token: Token::Number(num),
+                            span,
+                        }),
+                        Err(_) => return Err(Error::InvalidNumber(num_str, span)),
                     }
                 }
 
@@ -104,34 +264,74 @@ impl GlotLine {
                         ident.push(ch);
 
                         // Move the iterator forward
-                        chars.next();
+                        advance(&mut chars, &mut column);
                     }
 
                     // Check if it's a keyword or variable
                     match ident.as_str() {
-                        "LET" => tokens.push(Token::KeywordLet),
-                        "PRINT" => tokens.push(Token::KeywordPrint),
-                        "END" => tokens.push(Token::KeywordEnd),
+                        "LET" => tokens.push(PositionedToken {
+                            token: Token::KeywordLet,
+                            span,
+                        }),
+                        "PRINT" => tokens.push(PositionedToken {
+                            token: Token::KeywordPrint,
+                            span,
+                        }),
+                        "END" => tokens.push(PositionedToken {
+                            token: Token::KeywordEnd,
+                            span,
+                        }),
+                        "GOTO" => tokens.push(PositionedToken {
+                            token: Token::KeywordGoto,
+                            span,
+                        }),
+                        "IF" => tokens.push(PositionedToken {
+                            token: Token::KeywordIf,
+                            span,
+                        }),
+                        "THEN" => tokens.push(PositionedToken {
+                            token: Token::KeywordThen,
+                            span,
+                        }),
+                        "FOR" => tokens.push(PositionedToken {
+                            token: Token::KeywordFor,
+                            span,
+                        }),
+                        "TO" => tokens.push(PositionedToken {
+                            token: Token::KeywordTo,
+                            span,
+                        }),
+                        "STEP" => tokens.push(PositionedToken {
+                            token: Token::KeywordStep,
+                            span,
+                        }),
+                        "NEXT" => tokens.push(PositionedToken {
+                            token: Token::KeywordNext,
+                            span,
+                        }),
 
                         _ => {
                             // If not a keyword, check if it's a valid single-char variable
                             if ident.len() == 1 {
-                                tokens.push(Token::Identifier(
-                                    ident
-                                        .chars()
-                                        .next()
-                                        .ok_or(Error::InvalidIdentifier(ident))?,
-                                ));
+                                let variable = ident
+                                    .chars()
+                                    .next()
+                                    .ok_or_else(|| Error::InvalidIdentifier(ident.clone(), span))?;
+
+                                tokens.push(PositionedToken {
+                                    token: Token::Identifier(variable),
+                                    span,
+                                });
                             } else {
                                 // Multi-char variable is an error
-                                return Err(Error::InvalidIdentifier(ident));
+                                return Err(Error::InvalidIdentifier(ident, span));
                             }
                         }
                     }
                 }
 
                 _ => {
-                    return Err(Error::InvalidCharacter(c));
+                    return Err(Error::InvalidCharacter(c, span));
                 }
             }
         }
@@ -144,19 +344,108 @@ impl GlotLine {
 mod tests {
     use crate::Error;
     use crate::tokenizer::GlotLine;
+    use crate::tokenizer::Span;
     use crate::tokenizer::Token;
 
     #[test]
     fn test_tokenizer_print_var() -> Result<(), Error> {
         let line = "10 PRINT G";
         let expected_tokens = [
-            Token::Number(10),
+            Token::Number(10.0),
             Token::KeywordPrint,
             Token::Identifier('G'),
         ];
 
-        let glot_line = GlotLine::new(&line)?;
-        assert_eq!(glot_line.tokens, expected_tokens);
+        let glot_line = GlotLine::new(1, line)?;
+        let tokens: Vec<Token> = glot_line.tokens.iter().map(|pt| pt.token.clone()).collect();
+        assert_eq!(tokens, expected_tokens);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tokenizer_comparison_operators() -> Result<(), Error> {
+        let line = "A < B <= C > D >= E == F <> G = H";
+        let expected_tokens = [
+            Token::Identifier('A'),
+            Token::OperatorLess,
+            Token::Identifier('B'),
+            Token::OperatorLessEqual,
+            Token::Identifier('C'),
+            Token::OperatorGreater,
+            Token::Identifier('D'),
+            Token::OperatorGreaterEqual,
+            Token::Identifier('E'),
+            Token::OperatorEqual,
+            Token::Identifier('F'),
+            Token::OperatorNotEqual,
+            Token::Identifier('G'),
+            Token::Equals,
+            Token::Identifier('H'),
+        ];
+
+        let glot_line = GlotLine::new(1, line)?;
+        let tokens: Vec<Token> = glot_line.tokens.iter().map(|pt| pt.token.clone()).collect();
+        assert_eq!(tokens, expected_tokens);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tokenizer_floating_point_number() -> Result<(), Error> {
+        let line = "LET A = 2.5";
+        let expected_tokens = [
+            Token::KeywordLet,
+            Token::Identifier('A'),
+            Token::Equals,
+            Token::Number(2.5),
+        ];
+
+        let glot_line = GlotLine::new(1, line)?;
+        let tokens: Vec<Token> = glot_line.tokens.iter().map(|pt| pt.token.clone()).collect();
+        assert_eq!(tokens, expected_tokens);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tokenizer_invalid_number_with_two_dots() {
+        let result = GlotLine::new(1, "1.2.3");
+        assert!(matches!(result, Err(Error::InvalidNumber(_, _))));
+    }
+
+    #[test]
+    fn test_tokenizer_modulo_and_exponent_operators() -> Result<(), Error> {
+        let line = "A % B ^ C";
+        let expected_tokens = [
+            Token::Identifier('A'),
+            Token::OperatorModulo,
+            Token::Identifier('B'),
+            Token::OperatorExponent,
+            Token::Identifier('C'),
+        ];
+
+        let glot_line = GlotLine::new(1, line)?;
+        let tokens: Vec<Token> = glot_line.tokens.iter().map(|pt| pt.token.clone()).collect();
+        assert_eq!(tokens, expected_tokens);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tokenizer_tracks_spans() -> Result<(), Error> {
+        let glot_line = GlotLine::new(3, "LET A = 5")?;
+
+        let spans: Vec<Span> = glot_line.tokens.iter().map(|pt| pt.span).collect();
+        assert_eq!(
+            spans,
+            vec![
+                Span { line: 3, column: 1 },
+                Span { line: 3, column: 5 },
+                Span { line: 3, column: 7 },
+                Span { line: 3, column: 9 },
+            ]
+        );
 
         Ok(())
     }